@@ -0,0 +1,155 @@
+//! Image clipboard support, gated behind the `image-data` feature.
+//!
+//! This lets apps move raw bitmaps through the system pasteboard rather than just text and
+//! files, by bridging a tightly-packed RGBA pixel buffer through `CGImage`/`NSImage`.
+
+use core_foundation::base::CFRetain;
+use core_graphics::base::{kCGImageAlphaLast, kCGImageAlphaPremultipliedLast, kCGRenderingIntentDefault};
+use core_graphics::color_space::CGColorSpace;
+use core_graphics::data_provider::CGDataProvider;
+use core_graphics::image::CGImage;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::error::Error;
+use crate::foundation::{id, nil, NSArray, NSRect, NSSize};
+
+use super::Pasteboard;
+
+/// A raw, tightly-packed RGBA pixel buffer that can be moved through the system pasteboard.
+#[derive(Debug, Clone)]
+pub struct ImageData {
+    /// The width of the image, in pixels.
+    pub width: usize,
+
+    /// The height of the image, in pixels.
+    pub height: usize,
+
+    /// The image's pixels, as straight-alpha RGBA rows (4 bytes/pixel, `width * 4` bytes/row).
+    pub bytes: Vec<u8>
+}
+
+impl Pasteboard {
+    /// Copies an `ImageData` buffer to the pasteboard as a bitmap image.
+    pub fn copy_image(&self, image: &ImageData) {
+        let bytes_per_row = image.width * 4;
+        let color_space = CGColorSpace::create_device_rgb();
+        let provider = CGDataProvider::from_buffer(std::sync::Arc::new(image.bytes.clone()));
+
+        let cg_image = CGImage::new(
+            image.width,
+            image.height,
+            8,
+            32,
+            bytes_per_row,
+            &color_space,
+            kCGImageAlphaLast,
+            &provider,
+            false,
+            kCGRenderingIntentDefault
+        );
+
+        unsafe {
+            let size = NSSize::new(image.width as f64, image.height as f64);
+            let ns_image: id = msg_send![class!(NSImage), alloc];
+            let ns_image: id = msg_send![ns_image, initWithCGImage:cg_image.as_ptr() size:size];
+
+            let array = NSArray::new(&[ns_image]);
+            let _: () = msg_send![&*self.0, writeObjects: array];
+        }
+    }
+
+    /// Reads a bitmap image back off of the pasteboard, if one is present.
+    pub fn get_image(&self) -> Result<ImageData, Error> {
+        unsafe {
+            let class: id = msg_send![class!(NSImage), class];
+            let classes = NSArray::new(&[class]);
+            let contents: id = msg_send![&*self.0, readObjectsForClasses:classes options:nil];
+
+            // This can happen if the Pasteboard server has an error in returning items.
+            // In our case, we'll bubble up an error by checking the pasteboard.
+            if contents == nil {
+                return Err(Error {
+                    code: 666,
+                    domain: "com.cacao-rs.pasteboard".to_string(),
+                    description: "Pasteboard server returned no data.".to_string()
+                });
+            }
+
+            let images: Vec<id> = NSArray::retain(contents).into_iter().collect();
+
+            let ns_image = match images.first() {
+                Some(image) => *image,
+                None => {
+                    return Err(Error {
+                        code: 666,
+                        domain: "com.cacao-rs.pasteboard".to_string(),
+                        description: "Pasteboard server returned no data.".to_string()
+                    });
+                }
+            };
+
+            let zero_rect: NSRect = std::mem::zeroed();
+            let cg_image_ptr: *mut Object =
+                msg_send![ns_image, CGImageForProposedRect:&zero_rect context:nil hints:nil];
+
+            // `CGImageForProposedRect:context:hints:` hands back a reference owned by the
+            // `NSImage` (the Get rule - we must not release it). `CGImage::from_ptr` follows the
+            // Create rule and releases on drop, so retain here first to balance that.
+            CFRetain(cg_image_ptr as *const std::os::raw::c_void);
+            let cg_image = CGImage::from_ptr(cg_image_ptr as *mut _);
+
+            // We only know how to walk 8-bit-per-component, 4-bytes-per-pixel, alpha-last images
+            // below (i.e. what `copy_image` itself writes via `kCGImageAlphaLast`); bail out
+            // honestly on anything else (alpha-first layouts, grayscale, 16-bit-per-channel,
+            // CMYK, ...) rather than mis-mapping channels or indexing out of bounds.
+            let alpha_info = cg_image.alpha_info();
+            let alpha_last = alpha_info == kCGImageAlphaLast || alpha_info == kCGImageAlphaPremultipliedLast;
+
+            if cg_image.bits_per_pixel() != 32 || !alpha_last {
+                return Err(Error {
+                    code: 666,
+                    domain: "com.cacao-rs.pasteboard".to_string(),
+                    description: "Pasteboard image was not a 32-bit-per-pixel, alpha-last RGBA image.".to_string()
+                });
+            }
+
+            let premultiplied = alpha_info == kCGImageAlphaPremultipliedLast;
+            let width = cg_image.width();
+            let height = cg_image.height();
+            let bytes_per_row = cg_image.bytes_per_row();
+            let data = cg_image.data();
+            let pixels = data.bytes();
+
+            // `CGImage` rows may be padded out past `width * 4` to satisfy the stride, and the
+            // source pixels may be premultiplied. Normalize both away so callers get
+            // straight-alpha, tightly-packed RGBA rows back. We only un-premultiply when the
+            // image actually says it's premultiplied - `copy_image` writes straight alpha via
+            // `kCGImageAlphaLast`, and dividing that back out would corrupt it.
+            let mut bytes = Vec::with_capacity(width * height * 4);
+            for row in 0..height {
+                let row_start = row * bytes_per_row;
+
+                for col in 0..width {
+                    let offset = row_start + col * 4;
+                    let r = pixels[offset];
+                    let g = pixels[offset + 1];
+                    let b = pixels[offset + 2];
+                    let a = pixels[offset + 3];
+
+                    if !premultiplied || a == 0 {
+                        bytes.extend_from_slice(&[r, g, b, a]);
+                    } else {
+                        bytes.push((r as u32 * 255 / a as u32).min(255) as u8);
+                        bytes.push((g as u32 * 255 / a as u32).min(255) as u8);
+                        bytes.push((b as u32 * 255 / a as u32).min(255) as u8);
+                        bytes.push(a);
+                    }
+                }
+            }
+
+            Ok(ImageData { width, height, bytes })
+        }
+    }
+}
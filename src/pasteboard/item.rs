@@ -0,0 +1,77 @@
+//! Multi-representation pasteboard items.
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, NSArray, NSString};
+
+use super::types::PasteboardType;
+use super::Pasteboard;
+
+/// A single logical clipboard payload that can carry several representations of the same
+/// object - e.g. plain text, HTML, and a URL - so that receiving apps can pick whichever type
+/// suits them best. Build one up with `add_representation()`, then hand it (or several) to
+/// `Pasteboard::write_items()`.
+#[derive(Debug)]
+pub struct PasteboardItem {
+    representations: Vec<(PasteboardType, String)>
+}
+
+impl PasteboardItem {
+    /// Creates a new, empty item to accumulate representations onto.
+    pub fn new() -> Self {
+        PasteboardItem {
+            representations: Vec::new()
+        }
+    }
+
+    /// Adds a representation of `value` as `ptype` to this item.
+    pub fn add_representation<S: AsRef<str>>(mut self, ptype: PasteboardType, value: S) -> Self {
+        self.representations.push((ptype, value.as_ref().to_string()));
+        self
+    }
+
+    /// Builds the underlying `NSPasteboardItem`, setting each accumulated representation via
+    /// `setString:forType:`.
+    fn into_objc(self) -> ShareId<Object> {
+        unsafe {
+            let item: id = msg_send![class!(NSPasteboardItem), new];
+
+            for (ptype, value) in self.representations {
+                let contents = NSString::new(&value);
+                let ptype: NSString = ptype.into();
+                let _: bool = msg_send![item, setString:&*contents forType:ptype];
+            }
+
+            // `new` hands us an already-owned (+1) reference. Take ownership of that reference
+            // with `from_retained_ptr` instead of `from_ptr`, which would retain again and leak
+            // the `new` reference since nothing ever balances it with a release.
+            ShareId::from_retained_ptr(item)
+        }
+    }
+}
+
+impl Default for PasteboardItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pasteboard {
+    /// Writes several `PasteboardItem`s to the pasteboard in one `writeObjects:` call. Each item
+    /// can carry multiple representations of the same logical object (e.g. plain text and
+    /// HTML), and receiving apps can choose whichever type suits them.
+    pub fn write_items(&self, items: Vec<PasteboardItem>) {
+        // Keep the `ShareId`s alive through `writeObjects:` - they're what's actually holding
+        // each `NSPasteboardItem`'s reference count up while we hand raw pointers to `NSArray`.
+        let objc_items: Vec<ShareId<Object>> = items.into_iter().map(|item| item.into_objc()).collect();
+        let ptrs: Vec<id> = objc_items.iter().map(|item| &**item as *const Object as *mut Object).collect();
+
+        let array = NSArray::new(&ptrs[..]);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, writeObjects: array];
+        }
+    }
+}
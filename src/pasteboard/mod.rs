@@ -23,7 +23,16 @@ use url::Url;
 use crate::error::Error;
 use crate::foundation::{id, nil, NSArray, NSString, NSURL};
 
+#[cfg(feature = "image-data")]
+mod image;
+mod item;
+mod traits;
 mod types;
+
+#[cfg(feature = "image-data")]
+pub use image::ImageData;
+pub use item::PasteboardItem;
+pub use traits::{PasteboardReadObject, PasteboardWriteObject};
 pub use types::{PasteboardName, PasteboardType};
 
 /// Represents an `NSPasteboard`, enabling you to handle copy/paste/drag and drop.
@@ -59,12 +68,7 @@ impl Pasteboard {
 
     /// A shorthand helper method for copying some text to the clipboard.
     pub fn copy_text<S: AsRef<str>>(&self, text: S) {
-        let contents = NSString::new(text.as_ref());
-        let ptype: NSString = PasteboardType::String.into();
-
-        unsafe {
-            let _: () = msg_send![&*self.0, setString:&*contents forType:ptype];
-        }
+        let _ = self.write_object(text.as_ref().to_string());
     }
 
     /// A method for copying to the clipboard with a specified format.
@@ -77,6 +81,16 @@ impl Pasteboard {
         }
     }
 
+    /// A shorthand helper method for copying HTML markup to the clipboard.
+    pub fn copy_html<S: AsRef<str>>(&self, html: S) {
+        self.copy_clipboard(html, PasteboardType::Html);
+    }
+
+    /// A shorthand helper method for copying Rich Text Format data to the clipboard.
+    pub fn copy_rtf<S: AsRef<str>>(&self, rtf: S) {
+        self.copy_clipboard(rtf, PasteboardType::Rtf);
+    }
+
     /// A method for copying to the clipboard.
     pub fn copy_files<S: AsRef<str>>(&self, file_urls: Vec<S>) {
         let fmt_file_urls = file_urls.iter().map(|url| String::from("file://") + url.as_ref());
@@ -94,6 +108,19 @@ impl Pasteboard {
         }
     }
 
+    /// Copies a hyperlink to the clipboard the way browsers and Finder expect: an item carrying
+    /// both the `public.url` representation and a companion `public.url-name` title, falling
+    /// back to the URL text itself when no `title` is given.
+    pub fn copy_url(&self, url: &Url, title: Option<&str>) {
+        let title = title.unwrap_or_else(|| url.as_str());
+
+        let item = PasteboardItem::new()
+            .add_representation(PasteboardType::Url, url.as_str())
+            .add_representation(PasteboardType::UrlName, title);
+
+        self.write_items(vec![item]);
+    }
+
     /// Releases the receiver’s resources in the pasteboard server. It's rare-ish to need to use
     /// this, but considering this stuff happens on the Objective-C side you may need it.
     pub fn release_globally(&self) {
@@ -109,6 +136,21 @@ impl Pasteboard {
         }
     }
 
+    /// Returns the pasteboard's `changeCount`: a counter that monotonically increases every time
+    /// any process writes to this pasteboard. Comparing this against a previously-stored value
+    /// lets you detect that the pasteboard changed without re-reading and diffing its contents.
+    pub fn change_count(&self) -> i64 {
+        unsafe { msg_send![&*self.0, changeCount] }
+    }
+
+    /// A convenience wrapper around `change_count()` for polling: compares `last` against the
+    /// current count, and returns both whether it changed and the current count to store for the
+    /// next poll.
+    pub fn has_changed_since(&self, last: i64) -> (bool, i64) {
+        let current = self.change_count();
+        (current != last, current)
+    }
+
     /// Looks inside the pasteboard contents and extracts what FileURLs are there, if any.
     ///
     /// _Note that this method returns a list of `Url` entities, in an attempt to be closer to how
@@ -138,4 +180,33 @@ impl Pasteboard {
             Ok(urls)
         }
     }
+
+    /// Looks inside the pasteboard contents and extracts a plain-text string, if one is present.
+    ///
+    /// This is a shorthand for calling `get_string_for_type()` with `PasteboardType::String`.
+    pub fn get_text(&self) -> Result<String, Error> {
+        self.get_string_for_type(PasteboardType::String)
+    }
+
+    /// Looks inside the pasteboard contents and extracts the string representation of the given
+    /// `PasteboardType`, if one is present. This reads the specific `ptype` representation (via
+    /// `stringForType:`); `get_text()` is this method with `PasteboardType::String`.
+    pub fn get_string_for_type(&self, ptype: PasteboardType) -> Result<String, Error> {
+        unsafe {
+            let ptype: NSString = ptype.into();
+            let contents: id = msg_send![&*self.0, stringForType:ptype];
+
+            // This can happen if the Pasteboard server has an error in returning items, or if it
+            // simply has no representation of the requested type.
+            if contents == nil {
+                return Err(Error {
+                    code: 666,
+                    domain: "com.cacao-rs.pasteboard".to_string(),
+                    description: "Pasteboard server returned no data.".to_string()
+                });
+            }
+
+            Ok(NSString::retain(contents).to_str().to_string())
+        }
+    }
 }
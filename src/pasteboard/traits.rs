@@ -0,0 +1,97 @@
+//! Generic, typed read/write access to a `Pasteboard`.
+//!
+//! Rather than growing one `copy_*`/`get_*` method per data kind, `Pasteboard` implements
+//! [`PasteboardReadObject`] and [`PasteboardWriteObject`] for the Rust types it understands how
+//! to move through the clipboard. This gives third parties an extension point: implementing
+//! either trait for your own `T` lets it round-trip through a `Pasteboard` without needing to
+//! patch this crate.
+
+use objc::runtime::Object;
+use objc::{msg_send, sel, sel_impl};
+use url::Url;
+
+use crate::error::Error;
+use crate::foundation::{id, NSArray, NSString, NSURL};
+
+use super::types::PasteboardType;
+use super::Pasteboard;
+
+/// Implemented by types that can be read off of a `Pasteboard` as a concrete Rust value.
+pub trait PasteboardReadObject<T> {
+    /// The error type returned if reading fails.
+    type Err;
+
+    /// Attempts to read a value of type `T` off of the pasteboard.
+    fn read_object(&self) -> Result<T, Self::Err>;
+}
+
+/// Implemented by types that can be written to a `Pasteboard` from a concrete Rust value.
+pub trait PasteboardWriteObject<T> {
+    /// The error type returned if writing fails.
+    type Err;
+
+    /// Attempts to write `value` to the pasteboard.
+    fn write_object(&self, value: T) -> Result<(), Self::Err>;
+}
+
+impl PasteboardReadObject<String> for Pasteboard {
+    type Err = Error;
+
+    /// Reads the `NSString` representation off of the pasteboard, per `get_string_for_type()`.
+    fn read_object(&self) -> Result<String, Self::Err> {
+        self.get_string_for_type(PasteboardType::String)
+    }
+}
+
+impl PasteboardWriteObject<String> for Pasteboard {
+    type Err = Error;
+
+    /// Writes `value` to the pasteboard as a plain-text `NSString`.
+    fn write_object(&self, value: String) -> Result<(), Self::Err> {
+        let contents = NSString::new(&value);
+        let ptype: NSString = PasteboardType::String.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setString:&*contents forType:ptype];
+        }
+
+        Ok(())
+    }
+}
+
+impl PasteboardReadObject<Vec<Url>> for Pasteboard {
+    type Err = Box<dyn std::error::Error>;
+
+    /// Reads back whatever `NSURL` objects are on the pasteboard, parsed as `Url`.
+    ///
+    /// This builds on `get_file_urls()` rather than re-reading the board itself, so it returns the
+    /// same error when the board hands back no data. Any entry that doesn't parse as a `Url` is
+    /// skipped rather than failing the whole batch.
+    fn read_object(&self) -> Result<Vec<Url>, Self::Err> {
+        let urls = self.get_file_urls()?;
+
+        Ok(urls.iter().filter_map(|url| Url::parse(url.to_str()).ok()).collect())
+    }
+}
+
+impl PasteboardWriteObject<Vec<Url>> for Pasteboard {
+    type Err = Box<dyn std::error::Error>;
+
+    /// Writes `value` to the pasteboard as a set of `NSURL` objects.
+    fn write_object(&self, value: Vec<Url>) -> Result<(), Self::Err> {
+        let mut url_vec: Vec<id> = vec![];
+        for url in &value {
+            let temp = NSURL::with_str(url.as_str());
+            let ptr: *mut Object = &*temp.objc as *const Object as *mut Object;
+            url_vec.push(ptr);
+        }
+
+        let url_array = NSArray::new(&url_vec[..]);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, writeObjects: url_array];
+        }
+
+        Ok(())
+    }
+}
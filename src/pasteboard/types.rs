@@ -0,0 +1,79 @@
+//! Types used to describe pasteboards and the data placed on them.
+
+use crate::foundation::{id, NSString};
+
+// `NSPasteboardTypeHTML` and `NSPasteboardTypeRTF` are `NSString` constants exported by AppKit,
+// rather than UTIs we can spell out as string literals - so we link against them directly.
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {
+    static NSPasteboardTypeHTML: id;
+    static NSPasteboardTypeRTF: id;
+}
+
+/// Represents the name of a system pasteboard. macOS ships with a handful of "well known"
+/// pasteboards beyond the general one, which are used for specific system interactions (e.g. the
+/// Find panel, or font changes).
+#[derive(Debug)]
+pub enum PasteboardName {
+    /// The general pasteboard, used for standard copy/paste operations.
+    General,
+
+    /// The pasteboard used for font panel changes.
+    Font,
+
+    /// The pasteboard used for ruler changes.
+    Ruler,
+
+    /// The pasteboard used by the Find panel.
+    Find,
+
+    /// The pasteboard used for drag and drop operations.
+    Drag
+}
+
+impl From<PasteboardName> for NSString {
+    fn from(name: PasteboardName) -> Self {
+        NSString::new(match name {
+            PasteboardName::General => "NSGeneralPboard",
+            PasteboardName::Font => "NSFontPboard",
+            PasteboardName::Ruler => "NSRulerPboard",
+            PasteboardName::Find => "NSFindPboard",
+            PasteboardName::Drag => "NSDragPboard"
+        })
+    }
+}
+
+/// Represents the type of data being placed on, or read from, a pasteboard.
+#[derive(Debug)]
+pub enum PasteboardType {
+    /// Plain text.
+    String,
+
+    /// A file URL.
+    FileUrl,
+
+    /// A URL (not necessarily pointing at a local file).
+    Url,
+
+    /// The display title for a companion `Url` representation on the same pasteboard item.
+    UrlName,
+
+    /// HTML markup, as exchanged via `NSPasteboardTypeHTML`.
+    Html,
+
+    /// Rich Text Format data, as exchanged via `NSPasteboardTypeRTF`.
+    Rtf
+}
+
+impl From<PasteboardType> for NSString {
+    fn from(ptype: PasteboardType) -> Self {
+        match ptype {
+            PasteboardType::String => NSString::new("public.utf8-plain-text"),
+            PasteboardType::FileUrl => NSString::new("public.file-url"),
+            PasteboardType::Url => NSString::new("public.url"),
+            PasteboardType::UrlName => NSString::new("public.url-name"),
+            PasteboardType::Html => unsafe { NSString::retain(NSPasteboardTypeHTML) },
+            PasteboardType::Rtf => unsafe { NSString::retain(NSPasteboardTypeRTF) }
+        }
+    }
+}